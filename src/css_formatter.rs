@@ -0,0 +1,597 @@
+//! CSS tokenization, pretty-printing, and minification.
+//!
+//! Follows the classic CSS tokenizer token set (idents, functions,
+//! at-keywords, hash, dimension, percentage, delimiters) so selectors,
+//! declarations, at-rules, comments, and nested blocks all parse without
+//! pulling in a full CSS grammar implementation.
+
+use crate::types::IndentStyle;
+
+/// A single CSS token, as produced by [`tokenize`].
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum CssToken {
+    /// A run of whitespace, preserved verbatim from the source.
+    Whitespace(String),
+    /// A `/* ... */` comment, including the delimiters.
+    Comment(String),
+    /// A quoted string, including the surrounding quotes.
+    String(String),
+    /// A bare identifier, e.g. a selector, property name, or keyword value.
+    Ident(String),
+    /// An identifier immediately followed by `(`, e.g. `rgba` or `calc`.
+    Function(String),
+    /// An `@`-rule keyword, e.g. `@media`.
+    AtKeyword(String),
+    /// A `#`-prefixed hash token, e.g. a hex color or an ID selector.
+    Hash(String),
+    /// A bare number, e.g. `1`, `-0.5`, `1e3`.
+    Number(String),
+    /// A number immediately followed by a unit, e.g. `10px`.
+    Dimension(String),
+    /// A number immediately followed by `%`.
+    Percentage(String),
+    /// Any other single punctuation character, e.g. `{`, `}`, `:`, `;`, `,`.
+    Delim(char),
+}
+
+/// Tokenize a CSS source string into the classic CSS token set.
+pub(crate) fn tokenize(input: &str) -> Vec<CssToken> {
+    let chars: Vec<char> = input.chars().collect();
+    let len = chars.len();
+    let mut i = 0;
+    let mut tokens = Vec::new();
+
+    while i < len {
+        let c = chars[i];
+
+        if c.is_whitespace() {
+            let start = i;
+            while i < len && chars[i].is_whitespace() {
+                i += 1;
+            }
+            tokens.push(CssToken::Whitespace(chars[start..i].iter().collect()));
+            continue;
+        }
+
+        if c == '/' && i + 1 < len && chars[i + 1] == '*' {
+            let start = i;
+            i += 2;
+            while i + 1 < len && !(chars[i] == '*' && chars[i + 1] == '/') {
+                i += 1;
+            }
+            i = (i + 2).min(len);
+            tokens.push(CssToken::Comment(chars[start..i].iter().collect()));
+            continue;
+        }
+
+        if c == '"' || c == '\'' {
+            let (text, end) = parse_string(&chars, i);
+            tokens.push(CssToken::String(text));
+            i = end;
+            continue;
+        }
+
+        if c == '@' {
+            let start = i;
+            i += 1;
+            while i < len && is_ident_char(chars[i]) {
+                i += 1;
+            }
+            tokens.push(CssToken::AtKeyword(chars[start..i].iter().collect()));
+            continue;
+        }
+
+        if c == '#' && i + 1 < len && is_ident_char(chars[i + 1]) {
+            let start = i;
+            i += 1;
+            while i < len && is_ident_char(chars[i]) {
+                i += 1;
+            }
+            tokens.push(CssToken::Hash(chars[start..i].iter().collect()));
+            continue;
+        }
+
+        if is_number_start(&chars, i) {
+            let (text, end) = parse_numeric(&chars, i);
+            i = end;
+            tokens.push(text);
+            continue;
+        }
+
+        if is_ident_start(c) || (c == '-' && i + 1 < len && is_ident_start(chars[i + 1])) {
+            let start = i;
+            while i < len && is_ident_char(chars[i]) {
+                i += 1;
+            }
+            let text: String = chars[start..i].iter().collect();
+            if i < len && chars[i] == '(' {
+                tokens.push(CssToken::Function(text));
+            } else {
+                tokens.push(CssToken::Ident(text));
+            }
+            continue;
+        }
+
+        tokens.push(CssToken::Delim(c));
+        i += 1;
+    }
+
+    tokens
+}
+
+/// Parse a single- or double-quoted string, returns (text_with_quotes, end_position).
+fn parse_string(chars: &[char], start: usize) -> (String, usize) {
+    let quote = chars[start];
+    let len = chars.len();
+    let mut i = start + 1;
+    while i < len {
+        if chars[i] == '\\' && i + 1 < len {
+            i += 2;
+            continue;
+        }
+        if chars[i] == quote {
+            i += 1;
+            break;
+        }
+        i += 1;
+    }
+    (chars[start..i].iter().collect(), i)
+}
+
+/// Whether position `i` begins a CSS number (optionally signed, optionally a bare decimal).
+fn is_number_start(chars: &[char], i: usize) -> bool {
+    let len = chars.len();
+    let c = chars[i];
+    if c.is_ascii_digit() {
+        return true;
+    }
+    if c == '.' && i + 1 < len && chars[i + 1].is_ascii_digit() {
+        return true;
+    }
+    if c == '-' {
+        if i + 1 < len && chars[i + 1].is_ascii_digit() {
+            return true;
+        }
+        if i + 2 < len && chars[i + 1] == '.' && chars[i + 2].is_ascii_digit() {
+            return true;
+        }
+    }
+    false
+}
+
+/// Parse a number and, if immediately followed by a unit or `%`, the
+/// dimension/percentage it forms. Returns the classified token and end position.
+fn parse_numeric(chars: &[char], start: usize) -> (CssToken, usize) {
+    let len = chars.len();
+    let mut i = start;
+
+    if i < len && chars[i] == '-' {
+        i += 1;
+    }
+    while i < len && chars[i].is_ascii_digit() {
+        i += 1;
+    }
+    if i < len && chars[i] == '.' {
+        i += 1;
+        while i < len && chars[i].is_ascii_digit() {
+            i += 1;
+        }
+    }
+    if i < len && (chars[i] == 'e' || chars[i] == 'E') {
+        let mut j = i + 1;
+        if j < len && (chars[j] == '+' || chars[j] == '-') {
+            j += 1;
+        }
+        if j < len && chars[j].is_ascii_digit() {
+            i = j;
+            while i < len && chars[i].is_ascii_digit() {
+                i += 1;
+            }
+        }
+    }
+
+    if i < len && chars[i] == '%' {
+        i += 1;
+        (CssToken::Percentage(chars[start..i].iter().collect()), i)
+    } else if i < len && is_ident_start(chars[i]) {
+        while i < len && is_ident_char(chars[i]) {
+            i += 1;
+        }
+        (CssToken::Dimension(chars[start..i].iter().collect()), i)
+    } else {
+        (CssToken::Number(chars[start..i].iter().collect()), i)
+    }
+}
+
+fn is_ident_start(c: char) -> bool {
+    c.is_alphabetic() || c == '_' || c == '-' || !c.is_ascii()
+}
+
+fn is_ident_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '-' || !c.is_ascii()
+}
+
+/// Pretty-print CSS, reindenting each rule body with one declaration per line.
+///
+/// Selectors are left on a single line; declarations inside a block each get
+/// their own line and the body's indentation tracks brace nesting, so at-rules
+/// like `@media` that themselves contain nested rule blocks format correctly.
+///
+/// Only the first `:` of a declaration (the `property:` separator) inside a
+/// block gets a following space; every other `:` — pseudo-class/pseudo-element
+/// selectors like `a:hover`/`a::before`, and any later `:` in the same
+/// declaration's value (e.g. `url(http://...)`) — is left tight.
+///
+/// # Arguments
+/// * `input` - The CSS source to format
+/// * `indent` - The indentation style to use for each nesting level
+///
+/// # Returns
+/// * The reformatted CSS, always ending in a single trailing newline
+pub fn format_css(input: &str, indent: IndentStyle) -> String {
+    let tokens = tokenize(input);
+    let indent_str = indent.as_str();
+    let mut out = String::with_capacity(input.len() * 2);
+    let mut depth: usize = 0;
+    let mut pending_space = false;
+    // Whether we've already rendered this declaration's `property:` colon.
+    let mut seen_decl_colon = false;
+
+    for (idx, tok) in tokens.iter().enumerate() {
+        match tok {
+            CssToken::Whitespace(_) => {
+                pending_space = true;
+            }
+            CssToken::Comment(text) => {
+                push_word(&mut out, text, &mut pending_space);
+            }
+            CssToken::Delim('{') => {
+                out.push_str(" {");
+                depth += 1;
+                seen_decl_colon = false;
+                push_newline(&mut out, depth, &indent_str);
+                pending_space = false;
+            }
+            CssToken::Delim('}') => {
+                depth = depth.saturating_sub(1);
+                seen_decl_colon = false;
+                push_newline(&mut out, depth, &indent_str);
+                out.push('}');
+                push_newline(&mut out, depth, &indent_str);
+                pending_space = false;
+            }
+            CssToken::Delim(';') => {
+                out.push(';');
+                seen_decl_colon = false;
+                push_newline(&mut out, depth, &indent_str);
+                pending_space = false;
+            }
+            CssToken::Delim(':') => {
+                // A `:` that reaches a `{` before any `;`/`}` is part of a
+                // nested selector (e.g. `a:hover` inside `@media`).
+                let in_selector = matches!(
+                    tokens[idx + 1..]
+                        .iter()
+                        .find(|t| matches!(t, CssToken::Delim('{' | ';' | '}'))),
+                    Some(CssToken::Delim('{'))
+                );
+                if depth > 0 && !seen_decl_colon && !in_selector {
+                    out.push_str(": ");
+                    seen_decl_colon = true;
+                    pending_space = false;
+                } else {
+                    push_word(&mut out, ":", &mut pending_space);
+                }
+            }
+            CssToken::Delim(',') => {
+                out.push_str(", ");
+                pending_space = false;
+            }
+            CssToken::Delim(c) => {
+                push_word(&mut out, &c.to_string(), &mut pending_space);
+            }
+            CssToken::String(s)
+            | CssToken::Ident(s)
+            | CssToken::Function(s)
+            | CssToken::AtKeyword(s)
+            | CssToken::Hash(s)
+            | CssToken::Number(s)
+            | CssToken::Dimension(s)
+            | CssToken::Percentage(s) => {
+                push_word(&mut out, s, &mut pending_space);
+            }
+        }
+    }
+
+    while out.ends_with('\n') || out.ends_with(' ') {
+        out.pop();
+    }
+    out.push('\n');
+    out
+}
+
+/// Push a token's text, inserting a single separating space if one was
+/// pending and we're not at the start of a line.
+fn push_word(out: &mut String, text: &str, pending_space: &mut bool) {
+    if *pending_space && !out.is_empty() && !out.ends_with('\n') && !out.ends_with(' ') {
+        out.push(' ');
+    }
+    out.push_str(text);
+    *pending_space = false;
+}
+
+/// Trim trailing spaces, start a new line (unless already on one), and
+/// indent it to `depth`.
+fn push_newline(out: &mut String, depth: usize, indent_str: &str) {
+    while out.ends_with(' ') {
+        out.pop();
+    }
+    if !out.ends_with('\n') {
+        out.push('\n');
+    }
+    for _ in 0..depth {
+        out.push_str(indent_str);
+    }
+}
+
+/// Minify CSS by stripping comments and collapsing whitespace.
+///
+/// A single space is kept wherever whitespace appeared in the source, except
+/// immediately around `{`, `}`, `;`, `:`, and `,`, where it's always safe to
+/// drop. This is deliberately conservative: dropping space elsewhere could
+/// change meaning (e.g. the descendant combinator in `.a .b`, or the required
+/// space around `+`/`-` inside `calc()`), while keeping an unnecessary space
+/// never does. The optional semicolon before a closing `}` is also dropped,
+/// by holding each `;` token back until we know what follows it; since this
+/// operates on tokens rather than the rendered output, a `;` or `}` inside a
+/// `CssToken::String` is never mistaken for the declaration terminator.
+/// Comments carry no whitespace semantics of their own and are dropped
+/// outright rather than lowered to a space, so a comment directly between two
+/// tokens (e.g. `.a/* */.b`) doesn't turn a compound selector into a
+/// descendant combinator.
+///
+/// # Arguments
+/// * `input` - The CSS source to minify
+///
+/// # Returns
+/// * The minified CSS, with no leading or trailing whitespace
+pub fn minify_css(input: &str) -> String {
+    let tokens = tokenize(input);
+    let mut out = String::with_capacity(input.len());
+    let mut pending_space = false;
+    let mut suppress_next_space = true;
+    let mut pending_semicolon = false;
+
+    for tok in &tokens {
+        match tok {
+            CssToken::Whitespace(_) => {
+                if !suppress_next_space {
+                    pending_space = true;
+                }
+            }
+            CssToken::Comment(_) => {
+                // Dropped without touching `pending_space`/`suppress_next_space`:
+                // a comment is not itself whitespace, so it must not synthesize
+                // a separating space between two otherwise-adjacent tokens.
+            }
+            CssToken::Delim(';') => {
+                // Don't emit yet: if a `}` follows, this semicolon is the
+                // optional terminator of the block's last declaration.
+                if pending_semicolon {
+                    out.push(';');
+                }
+                pending_semicolon = true;
+                pending_space = false;
+                suppress_next_space = true;
+            }
+            CssToken::Delim('}') => {
+                // Drop a pending semicolon; it was redundant before `}`.
+                pending_semicolon = false;
+                out.push('}');
+                pending_space = false;
+                suppress_next_space = true;
+            }
+            CssToken::Delim(c) if "{,:".contains(*c) => {
+                if pending_semicolon {
+                    out.push(';');
+                    pending_semicolon = false;
+                }
+                out.push(*c);
+                pending_space = false;
+                suppress_next_space = true;
+            }
+            _ => {
+                if pending_semicolon {
+                    out.push(';');
+                    pending_semicolon = false;
+                }
+                if pending_space {
+                    out.push(' ');
+                }
+                match tok {
+                    CssToken::Delim(c) => out.push(*c),
+                    CssToken::String(s)
+                    | CssToken::Ident(s)
+                    | CssToken::Function(s)
+                    | CssToken::AtKeyword(s)
+                    | CssToken::Hash(s)
+                    | CssToken::Number(s)
+                    | CssToken::Dimension(s)
+                    | CssToken::Percentage(s) => out.push_str(s),
+                    _ => unreachable!("whitespace/comment/no-space-punct handled above"),
+                }
+                pending_space = false;
+                suppress_next_space = false;
+            }
+        }
+    }
+
+    if pending_semicolon {
+        out.push(';');
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tokenize_selector_and_declaration() {
+        let tokens = tokenize("a { color: red; }");
+        assert_eq!(
+            tokens,
+            vec![
+                CssToken::Ident("a".to_string()),
+                CssToken::Whitespace(" ".to_string()),
+                CssToken::Delim('{'),
+                CssToken::Whitespace(" ".to_string()),
+                CssToken::Ident("color".to_string()),
+                CssToken::Delim(':'),
+                CssToken::Whitespace(" ".to_string()),
+                CssToken::Ident("red".to_string()),
+                CssToken::Delim(';'),
+                CssToken::Whitespace(" ".to_string()),
+                CssToken::Delim('}'),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_tokenize_hash_dimension_percentage() {
+        let tokens = tokenize("#fff 10px 50%");
+        assert_eq!(
+            tokens,
+            vec![
+                CssToken::Hash("#fff".to_string()),
+                CssToken::Whitespace(" ".to_string()),
+                CssToken::Dimension("10px".to_string()),
+                CssToken::Whitespace(" ".to_string()),
+                CssToken::Percentage("50%".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_tokenize_function_and_at_keyword() {
+        let tokens = tokenize("@media rgba(0,0,0)");
+        assert_eq!(
+            tokens[0],
+            CssToken::AtKeyword("@media".to_string())
+        );
+        assert!(tokens.contains(&CssToken::Function("rgba".to_string())));
+    }
+
+    #[test]
+    fn test_tokenize_comment_and_string() {
+        let tokens = tokenize("/* note */ content: \"hi\";");
+        assert_eq!(tokens[0], CssToken::Comment("/* note */".to_string()));
+        assert!(tokens.contains(&CssToken::String("\"hi\"".to_string())));
+    }
+
+    #[test]
+    fn test_format_css_one_declaration_per_line() {
+        let input = "a{color:red;background:blue;}";
+        let result = format_css(input, IndentStyle::Spaces(2));
+        assert_eq!(result, "a {\n  color: red;\n  background: blue;\n}\n");
+    }
+
+    #[test]
+    fn test_format_css_nested_at_rule() {
+        let input = "@media screen{a{color:red;}}";
+        let result = format_css(input, IndentStyle::Spaces(2));
+        assert_eq!(
+            result,
+            "@media screen {\n  a {\n    color: red;\n  }\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_format_css_preserves_comments() {
+        let input = "a{/* note */color:red;}";
+        let result = format_css(input, IndentStyle::Spaces(2));
+        assert!(result.contains("/* note */"));
+    }
+
+    #[test]
+    fn test_format_css_pseudo_class_selector_stays_tight() {
+        let input = "a:hover{color:red;}";
+        let result = format_css(input, IndentStyle::Spaces(2));
+        assert_eq!(result, "a:hover {\n  color: red;\n}\n");
+    }
+
+    #[test]
+    fn test_format_css_pseudo_element_selector_stays_tight() {
+        let input = "a::before{content:\"x\";}";
+        let result = format_css(input, IndentStyle::Spaces(2));
+        assert_eq!(result, "a::before {\n  content: \"x\";\n}\n");
+    }
+
+    #[test]
+    fn test_format_css_unquoted_url_value_not_mangled() {
+        let input = "a{background:url(http://x.com/a.png);}";
+        let result = format_css(input, IndentStyle::Spaces(2));
+        assert_eq!(
+            result,
+            "a {\n  background: url(http://x.com/a.png);\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_minify_css_basic() {
+        let input = "a {\n  color: red;\n  background: blue;\n}\n";
+        let result = minify_css(input);
+        assert_eq!(result, "a{color:red;background:blue}");
+    }
+
+    #[test]
+    fn test_minify_css_strips_comments() {
+        let input = "a { /* note */ color: red; }";
+        let result = minify_css(input);
+        assert!(!result.contains("note"));
+        assert_eq!(result, "a{color:red}");
+    }
+
+    #[test]
+    fn test_minify_css_preserves_descendant_combinator_space() {
+        let input = ".a .b { color: red; }";
+        let result = minify_css(input);
+        assert_eq!(result, ".a .b{color:red}");
+    }
+
+    #[test]
+    fn test_minify_css_preserves_calc_spacing() {
+        let input = "a { width: calc(100% - 10px); }";
+        let result = minify_css(input);
+        assert_eq!(result, "a{width:calc(100% - 10px)}");
+    }
+
+    #[test]
+    fn test_minify_css_selector_list() {
+        let input = "a, b { color: red; }";
+        let result = minify_css(input);
+        assert_eq!(result, "a,b{color:red}");
+    }
+
+    #[test]
+    fn test_minify_css_preserves_semicolon_and_brace_inside_string() {
+        let input = "a{content:\";}\"}";
+        let result = minify_css(input);
+        assert_eq!(result, "a{content:\";}\"}");
+    }
+
+    #[test]
+    fn test_minify_css_comment_does_not_synthesize_space_in_compound_selector() {
+        let input = ".a/* note */.b{color:red;}";
+        let result = minify_css(input);
+        assert_eq!(result, ".a.b{color:red}");
+    }
+
+    #[test]
+    fn test_minify_css_comment_between_real_whitespace_still_spaced() {
+        let input = ".a /* note */ .b{color:red;}";
+        let result = minify_css(input);
+        assert_eq!(result, ".a .b{color:red}");
+    }
+}
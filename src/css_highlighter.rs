@@ -0,0 +1,210 @@
+//! CSS syntax highlighter, reusing the JSON highlighter's [`Theme`] palette
+//! and [`OutputTarget`] so CSS and JSON panes can share one color scheme.
+//!
+//! Token-to-color mapping, since a [`Theme`] has no CSS-specific fields:
+//! selectors and property names use `theme.key`, string literals use
+//! `theme.string`, numbers/dimensions/percentages and hash colors use
+//! `theme.number`, at-keywords use `theme.boolean`, braces/parens/brackets
+//! use `theme.bracket`, and `:`/`;`/`,` use `theme.punctuation`. Comments and
+//! whitespace pass through uncolored.
+
+use crate::css_formatter::{tokenize, CssToken};
+use crate::highlighter::{push_colored, push_plain, OutputTarget, Theme};
+
+/// Highlights a CSS string with a specific theme and output target.
+///
+/// Tracks brace depth to tell selectors from declaration bodies: identifiers
+/// before the first `:` inside a block are colored as property names
+/// (`theme.key`); after a `:`, up to the next `;` or `}`, they're colored as
+/// values. Outside any block (depth 0), identifiers are selectors and are
+/// colored with `theme.key` — including the pseudo-class/pseudo-element name
+/// after a selector `:`/`::`, since a `:` only starts a value when it occurs
+/// inside a block (depth > 0).
+///
+/// # Arguments
+/// * `input` - The CSS string to highlight
+/// * `theme` - The token colors to use
+/// * `target` - Whether to emit HTML `<span>` tags or ANSI escape sequences
+///
+/// # Returns
+/// * The highlighted string (HTML or ANSI, depending on `target`)
+/// * Empty string if input is empty
+pub fn highlight_css_with_theme(input: &str, theme: &Theme, target: OutputTarget) -> String {
+    if input.is_empty() {
+        return String::new();
+    }
+
+    let tokens = tokenize(input);
+    let mut output = String::with_capacity(input.len() * 3);
+    if target == OutputTarget::Html {
+        output.push_str("<pre style=\"margin:0;font-family:inherit;\">");
+    }
+
+    // Brace nesting depth; a `:` only starts a declaration value inside a
+    // block (depth > 0) — at depth 0 it's a selector pseudo-class/element.
+    let mut depth: usize = 0;
+    // Whether we're past the `:` of a declaration, i.e. looking at a value.
+    let mut in_value = false;
+
+    for tok in &tokens {
+        match tok {
+            CssToken::Whitespace(text) => {
+                for c in text.chars() {
+                    push_plain(&mut output, c, target);
+                }
+            }
+            CssToken::Comment(text) => {
+                for c in text.chars() {
+                    push_plain(&mut output, c, target);
+                }
+            }
+            CssToken::Delim(c @ ('{' | '}' | '(' | ')' | '[' | ']')) => {
+                push_colored(&mut output, &c.to_string(), theme.bracket, target);
+                if *c == '{' {
+                    depth += 1;
+                    in_value = false;
+                } else if *c == '}' {
+                    depth = depth.saturating_sub(1);
+                    in_value = false;
+                }
+            }
+            CssToken::Delim(':') => {
+                push_colored(&mut output, ":", theme.punctuation, target);
+                if depth > 0 {
+                    in_value = true;
+                }
+            }
+            CssToken::Delim(c @ (';' | ',')) => {
+                push_colored(&mut output, &c.to_string(), theme.punctuation, target);
+                if *c == ';' {
+                    in_value = false;
+                }
+            }
+            CssToken::Delim(c) => {
+                push_plain(&mut output, *c, target);
+            }
+            CssToken::String(s) => {
+                push_colored(&mut output, s, theme.string, target);
+            }
+            CssToken::AtKeyword(s) => {
+                push_colored(&mut output, s, theme.boolean, target);
+            }
+            CssToken::Hash(s) | CssToken::Number(s) | CssToken::Dimension(s) | CssToken::Percentage(s) => {
+                push_colored(&mut output, s, theme.number, target);
+            }
+            CssToken::Ident(s) | CssToken::Function(s) => {
+                let color = if in_value { theme.number } else { theme.key };
+                push_colored(&mut output, s, color, target);
+            }
+        }
+    }
+
+    if target == OutputTarget::Html {
+        output.push_str("</pre>");
+    }
+    output
+}
+
+/// Highlights a CSS string and returns HTML with inline styles, using the
+/// default dark [`Theme`].
+///
+/// # Arguments
+/// * `input` - The CSS string to highlight
+///
+/// # Returns
+/// * HTML string with inline styles for syntax highlighting
+/// * Empty string if input is empty
+pub fn highlight_css(input: &str) -> String {
+    highlight_css_with_theme(input, &Theme::default(), OutputTarget::Html)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_highlight_css_empty_input() {
+        let result = highlight_css("");
+        assert!(result.is_empty());
+    }
+
+    #[test]
+    fn test_highlight_css_basic() {
+        let input = "a { color: red; }";
+        let result = highlight_css(input);
+        assert!(result.contains("<span"));
+        assert!(result.contains("color"));
+        assert!(result.contains("red"));
+    }
+
+    #[test]
+    fn test_highlight_css_selector_and_property_use_key_color() {
+        let input = "a { color: red; }";
+        let result = highlight_css(input);
+        let theme = Theme::dark();
+        let key_span = format!("color:{}", theme.key.to_hex());
+        assert!(result.contains(&key_span));
+    }
+
+    #[test]
+    fn test_highlight_css_value_uses_number_color() {
+        let input = "div { width: 10px; }";
+        let result = highlight_css(input);
+        let theme = Theme::dark();
+        let value_span = format!("color:{}", theme.number.to_hex());
+        assert!(result.contains(&value_span));
+    }
+
+    #[test]
+    fn test_highlight_css_pseudo_class_uses_key_color_not_value_color() {
+        let input = "a:hover { color: red; }";
+        let result = highlight_css(input);
+        let theme = Theme::dark();
+        let hover_span = format!("color:{}\">hover", theme.key.to_hex());
+        assert!(result.contains(&hover_span));
+    }
+
+    #[test]
+    fn test_highlight_css_at_keyword_uses_boolean_color() {
+        let input = "@media screen { a { color: red; } }";
+        let result = highlight_css(input);
+        let theme = Theme::dark();
+        assert!(result.contains(&format!("color:{}", theme.boolean.to_hex())));
+    }
+
+    #[test]
+    fn test_highlight_css_hash_color_uses_number_color() {
+        let input = "a { color: #fff; }";
+        let result = highlight_css(input);
+        let theme = Theme::dark();
+        assert!(result.contains("#fff"));
+        assert!(result.contains(&format!("color:{}", theme.number.to_hex())));
+    }
+
+    #[test]
+    fn test_highlight_css_ansi_output_has_no_html() {
+        let input = "a { color: red; }";
+        let result = highlight_css_with_theme(input, &Theme::dark(), OutputTarget::Ansi);
+        assert!(!result.contains("<span"));
+        assert!(!result.contains("<pre"));
+        assert!(result.contains("\x1b[38;2;"));
+    }
+
+    #[test]
+    fn test_highlight_css_custom_theme() {
+        let custom = Theme {
+            key: crate::highlighter::Color::new(1, 2, 3),
+            ..Theme::dark()
+        };
+        let input = "a { color: red; }";
+        let result = highlight_css_with_theme(input, &custom, OutputTarget::Html);
+        assert!(result.contains("color:#010203"));
+    }
+
+    #[test]
+    fn test_highlight_css_preserves_comments_uncolored() {
+        let input = "/* note */ a { color: red; }";
+        let result = highlight_css(input);
+        assert!(result.contains("/* note */"));
+    }
+}
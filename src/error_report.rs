@@ -0,0 +1,144 @@
+//! Rendering [`FormatError`]s as GitHub/rustc-style source error panels:
+//! the offending line, a caret under the exact column, and a couple of
+//! lines of surrounding context.
+
+use crate::highlighter::{highlight_json_with_theme, OutputTarget, Theme};
+use crate::types::FormatError;
+
+/// Number of context lines shown above and below the offending line.
+const CONTEXT_LINES: usize = 2;
+
+impl FormatError {
+    /// Render this error as a multi-line, rustc-style source panel: an
+    /// `error: message` header, a `--> line N, column M` pointer, the
+    /// numbered context lines, and a caret under the error column.
+    ///
+    /// # Arguments
+    /// * `input` - The original source the error was produced from
+    pub fn render_with_source(&self, input: &str) -> String {
+        render_panel(self, input, None)
+    }
+
+    /// Like [`FormatError::render_with_source`], but syntax-highlights the
+    /// shown context using `highlight_json`'s state machine and tints the
+    /// caret pointing at the error column.
+    ///
+    /// # Arguments
+    /// * `input` - The original source the error was produced from
+    /// * `theme` - The token colors to highlight the context with
+    /// * `target` - Whether to emit HTML `<span>` tags or ANSI escapes
+    pub fn render_with_source_highlighted(
+        &self,
+        input: &str,
+        theme: &Theme,
+        target: OutputTarget,
+    ) -> String {
+        render_panel(self, input, Some((theme, target)))
+    }
+}
+
+fn render_panel(error: &FormatError, input: &str, highlight: Option<(&Theme, OutputTarget)>) -> String {
+    let lines: Vec<&str> = input.lines().collect();
+    let error_line_idx = error.line.saturating_sub(1);
+    let start = error_line_idx.saturating_sub(CONTEXT_LINES);
+    let end = (error_line_idx + CONTEXT_LINES + 1).min(lines.len());
+    let gutter_width = end.to_string().len().max(1);
+
+    let mut out = String::new();
+    out.push_str(&format!("error: {}\n", error.message));
+    out.push_str(&format!(
+        "{}--> line {}, column {}\n",
+        " ".repeat(gutter_width),
+        error.line,
+        error.column
+    ));
+
+    for line_no in start..end {
+        let display_no = line_no + 1;
+        let raw_line = lines.get(line_no).copied().unwrap_or("");
+        let rendered_line = match highlight {
+            Some((theme, target)) => highlight_json_with_theme(raw_line, theme, target),
+            None => raw_line.to_string(),
+        };
+        out.push_str(&format!(
+            "{:>width$} | {}\n",
+            display_no,
+            rendered_line,
+            width = gutter_width
+        ));
+
+        if display_no == error.line {
+            let caret_col = error.column.saturating_sub(1);
+            let caret = match highlight {
+                Some((_, OutputTarget::Ansi)) => "\x1b[1;31m^\x1b[0m".to_string(),
+                Some((_, OutputTarget::Html)) => {
+                    "<span style=\"color:#ff0000;font-weight:bold\">^</span>".to_string()
+                }
+                None => "^".to_string(),
+            };
+            out.push_str(&format!(
+                "{} | {}{}\n",
+                " ".repeat(gutter_width),
+                " ".repeat(caret_col),
+                caret
+            ));
+        }
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_with_source_points_at_error() {
+        let input = "{\n  \"key\": invalid\n}";
+        let error = FormatError::new("expected value", 2, 10);
+        let rendered = error.render_with_source(input);
+        assert!(rendered.contains("expected value"));
+        assert!(rendered.contains("\"key\": invalid"));
+        let caret_line = rendered.lines().find(|l| l.contains('^')).unwrap();
+        assert_eq!(caret_line.chars().filter(|&c| c == '^').count(), 1);
+    }
+
+    #[test]
+    fn test_render_with_source_includes_context_lines() {
+        let input = "{\n  \"a\": 1,\n  \"b\": invalid,\n  \"c\": 3\n}";
+        let error = FormatError::new("expected value", 3, 9);
+        let rendered = error.render_with_source(input);
+        assert!(rendered.contains("\"a\": 1"));
+        assert!(rendered.contains("\"b\": invalid"));
+        assert!(rendered.contains("\"c\": 3"));
+    }
+
+    #[test]
+    fn test_render_with_source_highlighted_html() {
+        let input = "{\n  \"key\": invalid\n}";
+        let error = FormatError::new("expected value", 2, 10);
+        let rendered =
+            error.render_with_source_highlighted(input, &Theme::dark(), OutputTarget::Html);
+        assert!(rendered.contains("<span"));
+        assert!(rendered.contains("color:#ff0000"));
+    }
+
+    #[test]
+    fn test_render_with_source_highlighted_ansi() {
+        let input = "{\n  \"key\": invalid\n}";
+        let error = FormatError::new("expected value", 2, 10);
+        let rendered =
+            error.render_with_source_highlighted(input, &Theme::dark(), OutputTarget::Ansi);
+        assert!(rendered.contains("\x1b[38;2;"));
+        assert!(rendered.contains("\x1b[1;31m"));
+    }
+
+    #[test]
+    fn test_render_with_source_first_line_error() {
+        let input = "{invalid}";
+        let error = FormatError::new("expected value", 1, 2);
+        let rendered = error.render_with_source(input);
+        assert!(rendered.contains("{invalid}"));
+        assert!(rendered.contains('^'));
+    }
+}
@@ -1,8 +1,11 @@
-use crate::types::{FormatError, IndentStyle};
+use crate::types::{FormatError, FormatOptions, IndentStyle, KeyOrder};
 use serde_json::Value;
 
 /// Minify JSON by removing all unnecessary whitespace.
 ///
+/// Object keys are preserved in source order (see [`crate::types::KeyOrder`]);
+/// use [`minify_json_with_options`] to request alphabetical sorting instead.
+///
 /// # Arguments
 /// * `input` - The JSON string to minify
 ///
@@ -10,18 +13,38 @@ use serde_json::Value;
 /// * `Ok(String)` - The minified JSON string
 /// * `Err(FormatError)` - Error with line/column position if JSON is invalid
 pub fn minify_json(input: &str) -> Result<String, FormatError> {
-    let value: Value = serde_json::from_str(input).map_err(|e| {
+    minify_json_with_options(input, FormatOptions::default())
+}
+
+/// Minify JSON with explicit formatting options.
+///
+/// # Arguments
+/// * `input` - The JSON string to minify
+/// * `options` - Formatting options; `key_order` and `ascii_only` affect
+///   minification, `indent` does not
+///
+/// # Returns
+/// * `Ok(String)` - The minified JSON string
+/// * `Err(FormatError)` - Error with line/column position if JSON is invalid
+pub fn minify_json_with_options(input: &str, options: FormatOptions) -> Result<String, FormatError> {
+    let mut value: Value = serde_json::from_str(input).map_err(|e| {
         FormatError::new(e.to_string(), e.line(), e.column())
     })?;
 
-    // serde_json::to_string produces compact JSON without whitespace
-    serde_json::to_string(&value).map_err(|e| {
-        FormatError::new(e.to_string(), 0, 0)
-    })
+    if options.key_order == KeyOrder::Sorted {
+        sort_keys_recursive(&mut value);
+    }
+
+    let mut output = String::with_capacity(input.len());
+    minify_value(&value, options.ascii_only, &mut output);
+    Ok(output)
 }
 
 /// Format JSON with the specified indentation style.
 ///
+/// Object keys are preserved in source order; use [`format_json_with_options`]
+/// to request alphabetical sorting instead.
+///
 /// # Arguments
 /// * `input` - The JSON string to format
 /// * `indent` - The indentation style to use
@@ -30,7 +53,20 @@ pub fn minify_json(input: &str) -> Result<String, FormatError> {
 /// * `Ok(String)` - The formatted JSON string
 /// * `Err(FormatError)` - Error with line/column position if JSON is invalid
 pub fn format_json(input: &str, indent: IndentStyle) -> Result<String, FormatError> {
-    let value: Value = serde_json::from_str(input).map_err(|e| {
+    format_json_with_options(input, FormatOptions { indent, ..FormatOptions::default() })
+}
+
+/// Format JSON with full control over indentation and key ordering.
+///
+/// # Arguments
+/// * `input` - The JSON string to format
+/// * `options` - The indentation style and key ordering to use
+///
+/// # Returns
+/// * `Ok(String)` - The formatted JSON string
+/// * `Err(FormatError)` - Error with line/column position if JSON is invalid
+pub fn format_json_with_options(input: &str, options: FormatOptions) -> Result<String, FormatError> {
+    let mut value: Value = serde_json::from_str(input).map_err(|e| {
         FormatError::new(
             e.to_string(),
             e.line(),
@@ -38,35 +74,54 @@ pub fn format_json(input: &str, indent: IndentStyle) -> Result<String, FormatErr
         )
     })?;
 
-    let indent_str = indent.as_str();
+    if options.key_order == KeyOrder::Sorted {
+        sort_keys_recursive(&mut value);
+    }
+
+    let indent_str = options.indent.as_str();
     let mut output = String::with_capacity(input.len() * 2);
-    format_value(&value, &indent_str, 0, &mut output);
+    format_value(&value, &indent_str, 0, options.ascii_only, &mut output);
     Ok(output)
 }
 
+/// Recursively sort object keys alphabetically in place.
+///
+/// Relies on serde_json's `preserve_order` feature (`Map` backed by
+/// `IndexMap`), whose `sort_keys` only sorts one level at a time.
+fn sort_keys_recursive(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            map.sort_keys();
+            for v in map.values_mut() {
+                sort_keys_recursive(v);
+            }
+        }
+        Value::Array(arr) => {
+            for v in arr.iter_mut() {
+                sort_keys_recursive(v);
+            }
+        }
+        _ => {}
+    }
+}
+
 /// Recursively format a JSON value with proper indentation.
-fn format_value(value: &Value, indent_str: &str, depth: usize, output: &mut String) {
+pub(crate) fn format_value(
+    value: &Value,
+    indent_str: &str,
+    depth: usize,
+    ascii_only: bool,
+    output: &mut String,
+) {
     match value {
         Value::Null => output.push_str("null"),
         Value::Bool(b) => output.push_str(if *b { "true" } else { "false" }),
-        Value::Number(n) => output.push_str(&n.to_string()),
-        Value::String(s) => {
-            output.push('"');
-            for c in s.chars() {
-                match c {
-                    '"' => output.push_str("\\\""),
-                    '\\' => output.push_str("\\\\"),
-                    '\n' => output.push_str("\\n"),
-                    '\r' => output.push_str("\\r"),
-                    '\t' => output.push_str("\\t"),
-                    c if c.is_control() => {
-                        output.push_str(&format!("\\u{:04x}", c as u32));
-                    }
-                    c => output.push(c),
-                }
-            }
-            output.push('"');
-        }
+        // With the `arbitrary_precision` feature, `Number` stores the
+        // original lexical token verbatim, so `as_str()` round-trips huge
+        // integers and high-precision decimals that would otherwise be
+        // rounded through i64/u64/f64.
+        Value::Number(n) => output.push_str(n.as_str()),
+        Value::String(s) => write_escaped_string(s, ascii_only, output),
         Value::Array(arr) => {
             if arr.is_empty() {
                 output.push_str("[]");
@@ -74,7 +129,7 @@ fn format_value(value: &Value, indent_str: &str, depth: usize, output: &mut Stri
                 output.push_str("[\n");
                 for (i, item) in arr.iter().enumerate() {
                     push_indent(output, indent_str, depth + 1);
-                    format_value(item, indent_str, depth + 1, output);
+                    format_value(item, indent_str, depth + 1, ascii_only, output);
                     if i < arr.len() - 1 {
                         output.push(',');
                     }
@@ -92,10 +147,9 @@ fn format_value(value: &Value, indent_str: &str, depth: usize, output: &mut Stri
                 let len = obj.len();
                 for (i, (key, val)) in obj.iter().enumerate() {
                     push_indent(output, indent_str, depth + 1);
-                    output.push('"');
-                    output.push_str(key);
-                    output.push_str("\": ");
-                    format_value(val, indent_str, depth + 1, output);
+                    write_escaped_string(key, ascii_only, output);
+                    output.push_str(": ");
+                    format_value(val, indent_str, depth + 1, ascii_only, output);
                     if i < len - 1 {
                         output.push(',');
                     }
@@ -108,6 +162,66 @@ fn format_value(value: &Value, indent_str: &str, depth: usize, output: &mut Stri
     }
 }
 
+/// Recursively write a JSON value in compact form (no whitespace).
+fn minify_value(value: &Value, ascii_only: bool, output: &mut String) {
+    match value {
+        Value::Null => output.push_str("null"),
+        Value::Bool(b) => output.push_str(if *b { "true" } else { "false" }),
+        Value::Number(n) => output.push_str(n.as_str()),
+        Value::String(s) => write_escaped_string(s, ascii_only, output),
+        Value::Array(arr) => {
+            output.push('[');
+            for (i, item) in arr.iter().enumerate() {
+                if i > 0 {
+                    output.push(',');
+                }
+                minify_value(item, ascii_only, output);
+            }
+            output.push(']');
+        }
+        Value::Object(obj) => {
+            output.push('{');
+            for (i, (key, val)) in obj.iter().enumerate() {
+                if i > 0 {
+                    output.push(',');
+                }
+                write_escaped_string(key, ascii_only, output);
+                output.push(':');
+                minify_value(val, ascii_only, output);
+            }
+            output.push('}');
+        }
+    }
+}
+
+/// Write a JSON string literal, escaping quotes, backslashes, and control
+/// characters. When `ascii_only` is set, every code point above U+007F is
+/// also escaped as `\uXXXX`, with astral code points split into UTF-16
+/// surrogate pairs, so the result is strictly 7-bit ASCII.
+fn write_escaped_string(s: &str, ascii_only: bool, output: &mut String) {
+    output.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => output.push_str("\\\""),
+            '\\' => output.push_str("\\\\"),
+            '\n' => output.push_str("\\n"),
+            '\r' => output.push_str("\\r"),
+            '\t' => output.push_str("\\t"),
+            c if c.is_control() => {
+                output.push_str(&format!("\\u{:04x}", c as u32));
+            }
+            c if ascii_only && !c.is_ascii() => {
+                let mut units = [0u16; 2];
+                for unit in c.encode_utf16(&mut units) {
+                    output.push_str(&format!("\\u{:04x}", unit));
+                }
+            }
+            c => output.push(c),
+        }
+    }
+    output.push('"');
+}
+
 /// Push indentation to the output string.
 fn push_indent(output: &mut String, indent_str: &str, depth: usize) {
     for _ in 0..depth {
@@ -127,6 +241,59 @@ mod tests {
         assert!(result.contains("\"value\": 42"));
     }
 
+    #[test]
+    fn test_format_preserves_source_key_order() {
+        let input = r#"{"name":"John","age":30}"#;
+        let result = format_json(input, IndentStyle::Spaces(2)).unwrap();
+        let expected = r#"{
+  "name": "John",
+  "age": 30
+}"#;
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_format_with_options_sorted_keys() {
+        let input = r#"{"name":"John","age":30}"#;
+        let options = FormatOptions { indent: IndentStyle::Spaces(2), key_order: KeyOrder::Sorted, ..FormatOptions::default() };
+        let result = format_json_with_options(input, options).unwrap();
+        let expected = r#"{
+  "age": 30,
+  "name": "John"
+}"#;
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_format_with_options_sorted_keys_nested() {
+        let input = r#"{"b":{"z":1,"a":2},"a":1}"#;
+        let options = FormatOptions { indent: IndentStyle::Spaces(2), key_order: KeyOrder::Sorted, ..FormatOptions::default() };
+        let result = format_json_with_options(input, options).unwrap();
+        let expected = r#"{
+  "a": 1,
+  "b": {
+    "a": 2,
+    "z": 1
+  }
+}"#;
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_minify_preserves_source_key_order() {
+        let input = r#"{"b":1,"a":2}"#;
+        let result = minify_json(input).unwrap();
+        assert_eq!(result, r#"{"b":1,"a":2}"#);
+    }
+
+    #[test]
+    fn test_minify_with_options_sorted_keys() {
+        let input = r#"{"b":1,"a":2}"#;
+        let options = FormatOptions { indent: IndentStyle::default(), key_order: KeyOrder::Sorted, ..FormatOptions::default() };
+        let result = minify_json_with_options(input, options).unwrap();
+        assert_eq!(result, r#"{"a":2,"b":1}"#);
+    }
+
     #[test]
     fn test_format_empty_object() {
         let input = "{}";
@@ -183,4 +350,68 @@ mod tests {
         let result = minify_json(input);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_format_preserves_huge_integer() {
+        let input = r#"{"big":123456789012345678901234567890}"#;
+        let result = format_json(input, IndentStyle::Spaces(2)).unwrap();
+        assert!(result.contains("123456789012345678901234567890"));
+    }
+
+    #[test]
+    fn test_format_preserves_long_decimal() {
+        let input = r#"{"tiny":2.22507385850720113605740979670913197593481954635164564e-308}"#;
+        let result = format_json(input, IndentStyle::Spaces(2)).unwrap();
+        assert!(result.contains("2.22507385850720113605740979670913197593481954635164564e-308"));
+    }
+
+    #[test]
+    fn test_format_preserves_long_exponent() {
+        let input = r#"{"huge":1.5e999999999999999999}"#;
+        let result = format_json(input, IndentStyle::Spaces(2)).unwrap();
+        // The exponent is emitted as written, with no sign added.
+        assert!(result.contains("1.5e999999999999999999"));
+    }
+
+    #[test]
+    fn test_minify_preserves_huge_integer() {
+        let input = r#"{"big":123456789012345678901234567890}"#;
+        let result = minify_json(input).unwrap();
+        assert!(result.contains("123456789012345678901234567890"));
+    }
+
+    #[test]
+    fn test_format_ascii_only_escapes_non_ascii() {
+        let input = r#"{"chinese":"你好"}"#;
+        let options = FormatOptions { ascii_only: true, ..FormatOptions::default() };
+        let result = format_json_with_options(input, options).unwrap();
+        assert!(result.contains("\\u4f60\\u597d"));
+        assert!(!result.contains('你'));
+        assert!(result.is_ascii());
+    }
+
+    #[test]
+    fn test_format_ascii_only_escapes_astral_as_surrogate_pair() {
+        let input = "{\"emoji\":\"😀\"}";
+        let options = FormatOptions { ascii_only: true, ..FormatOptions::default() };
+        let result = format_json_with_options(input, options).unwrap();
+        // U+1F600 GRINNING FACE splits into the UTF-16 surrogate pair D83D DE00.
+        assert!(result.contains("\\ud83d\\ude00"));
+        assert!(result.is_ascii());
+    }
+
+    #[test]
+    fn test_minify_ascii_only_escapes_non_ascii() {
+        let input = r#"{"chinese":"你好"}"#;
+        let options = FormatOptions { ascii_only: true, ..FormatOptions::default() };
+        let result = minify_json_with_options(input, options).unwrap();
+        assert_eq!(result, "{\"chinese\":\"\\u4f60\\u597d\"}");
+    }
+
+    #[test]
+    fn test_ascii_only_defaults_to_false() {
+        let input = r#"{"chinese":"你好"}"#;
+        let result = format_json(input, IndentStyle::Spaces(2)).unwrap();
+        assert!(result.contains("你好"));
+    }
 }
@@ -2,19 +2,106 @@
 //!
 //! Provides syntax highlighting for JSON using a simple state machine parser.
 //! Avoids syntect's binary serialization which has WASM compatibility issues.
+//! The same state machine can render to HTML (inline `<span>` styles) or to
+//! ANSI terminal escape sequences via [`OutputTarget`], and the seven token
+//! colors are configurable through [`Theme`].
+
+/// An RGB color used for syntax highlighting.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Color {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl Color {
+    pub const fn new(r: u8, g: u8, b: u8) -> Self {
+        Self { r, g, b }
+    }
+
+    /// Render as a CSS hex color string, e.g. `#ce9178`.
+    pub(crate) fn to_hex(self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.r, self.g, self.b)
+    }
+
+    /// Render as an ANSI 24-bit SGR foreground color escape sequence.
+    pub(crate) fn to_ansi_fg(self) -> String {
+        format!("\x1b[38;2;{};{};{}m", self.r, self.g, self.b)
+    }
+}
+
+/// Colors for each highlighted JSON token kind.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Theme {
+    pub string: Color,
+    pub key: Color,
+    pub number: Color,
+    pub boolean: Color,
+    pub null: Color,
+    pub bracket: Color,
+    pub punctuation: Color,
+}
+
+impl Theme {
+    /// The original VS Code dark theme-inspired palette.
+    pub const fn dark() -> Self {
+        Self {
+            string: Color::new(0xce, 0x91, 0x78),
+            key: Color::new(0x9c, 0xdc, 0xfe),
+            number: Color::new(0xb5, 0xce, 0xa8),
+            boolean: Color::new(0x56, 0x9c, 0xd6),
+            null: Color::new(0x56, 0x9c, 0xd6),
+            bracket: Color::new(0xff, 0xd7, 0x00),
+            punctuation: Color::new(0xd4, 0xd4, 0xd4),
+        }
+    }
+
+    /// A palette tuned for light backgrounds.
+    pub const fn light() -> Self {
+        Self {
+            string: Color::new(0xa3, 0x15, 0x15),
+            key: Color::new(0x08, 0x66, 0x78),
+            number: Color::new(0x09, 0x86, 0x58),
+            boolean: Color::new(0x00, 0x00, 0xee),
+            null: Color::new(0x00, 0x00, 0xee),
+            bracket: Color::new(0x80, 0x00, 0x80),
+            punctuation: Color::new(0x33, 0x33, 0x33),
+        }
+    }
 
-/// Color palette (VS Code dark theme inspired)
-mod colors {
-    pub const STRING: &str = "#ce9178";      // Orange-ish for strings
-    pub const KEY: &str = "#9cdcfe";         // Light blue for keys
-    pub const NUMBER: &str = "#b5cea8";      // Light green for numbers
-    pub const BOOLEAN: &str = "#569cd6";     // Blue for booleans
-    pub const NULL: &str = "#569cd6";        // Blue for null
-    pub const BRACKET: &str = "#ffd700";     // Gold for brackets
-    pub const PUNCTUATION: &str = "#d4d4d4"; // Gray for colons, commas
+    /// A high-contrast, accessibility-focused palette.
+    pub const fn high_contrast() -> Self {
+        Self {
+            string: Color::new(0xff, 0xff, 0x00),
+            key: Color::new(0x00, 0xff, 0xff),
+            number: Color::new(0x00, 0xff, 0x00),
+            boolean: Color::new(0xff, 0x80, 0x00),
+            null: Color::new(0xff, 0x80, 0x00),
+            bracket: Color::new(0xff, 0xff, 0xff),
+            punctuation: Color::new(0xff, 0xff, 0xff),
+        }
+    }
+}
+
+impl Default for Theme {
+    /// Defaults to [`Theme::dark`], matching the highlighter's original palette.
+    fn default() -> Self {
+        Theme::dark()
+    }
+}
+
+/// Where highlighted output should be rendered.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum OutputTarget {
+    /// HTML with inline `<span style="color:...">` tags.
+    #[default]
+    Html,
+    /// ANSI SGR escape sequences, for terminal/CLI output.
+    Ansi,
 }
 
-/// Highlights JSON string and returns HTML with inline styles.
+/// Highlights JSON string and returns HTML with inline styles, using the
+/// default dark [`Theme`].
 ///
 /// # Arguments
 /// * `input` - The JSON string to highlight
@@ -23,12 +110,28 @@ mod colors {
 /// * HTML string with inline styles for syntax highlighting
 /// * Empty string if input is empty
 pub fn highlight_json(input: &str) -> String {
+    highlight_json_with_theme(input, &Theme::default(), OutputTarget::Html)
+}
+
+/// Highlights a JSON string with a specific theme and output target.
+///
+/// # Arguments
+/// * `input` - The JSON string to highlight
+/// * `theme` - The token colors to use
+/// * `target` - Whether to emit HTML `<span>` tags or ANSI escape sequences
+///
+/// # Returns
+/// * The highlighted string (HTML or ANSI, depending on `target`)
+/// * Empty string if input is empty
+pub fn highlight_json_with_theme(input: &str, theme: &Theme, target: OutputTarget) -> String {
     if input.is_empty() {
         return String::new();
     }
 
     let mut output = String::with_capacity(input.len() * 3);
-    output.push_str("<pre style=\"margin:0;font-family:inherit;\">");
+    if target == OutputTarget::Html {
+        output.push_str("<pre style=\"margin:0;font-family:inherit;\">");
+    }
 
     let chars: Vec<char> = input.chars().collect();
     let len = chars.len();
@@ -50,7 +153,7 @@ pub fn highlight_json(input: &str) -> String {
 
             // Object start
             '{' => {
-                push_colored(&mut output, "{", colors::BRACKET);
+                push_colored(&mut output, "{", theme.bracket, target);
                 brace_stack.push('{');
                 expect_key = true;
                 i += 1;
@@ -58,7 +161,7 @@ pub fn highlight_json(input: &str) -> String {
 
             // Object end
             '}' => {
-                push_colored(&mut output, "}", colors::BRACKET);
+                push_colored(&mut output, "}", theme.bracket, target);
                 brace_stack.pop();
                 expect_key = false;
                 i += 1;
@@ -66,7 +169,7 @@ pub fn highlight_json(input: &str) -> String {
 
             // Array start
             '[' => {
-                push_colored(&mut output, "[", colors::BRACKET);
+                push_colored(&mut output, "[", theme.bracket, target);
                 brace_stack.push('[');
                 expect_key = false;
                 i += 1;
@@ -74,7 +177,7 @@ pub fn highlight_json(input: &str) -> String {
 
             // Array end
             ']' => {
-                push_colored(&mut output, "]", colors::BRACKET);
+                push_colored(&mut output, "]", theme.bracket, target);
                 brace_stack.pop();
                 expect_key = false;
                 i += 1;
@@ -82,14 +185,14 @@ pub fn highlight_json(input: &str) -> String {
 
             // Colon (key-value separator)
             ':' => {
-                push_colored(&mut output, ":", colors::PUNCTUATION);
+                push_colored(&mut output, ":", theme.punctuation, target);
                 expect_key = false;
                 i += 1;
             }
 
             // Comma
             ',' => {
-                push_colored(&mut output, ",", colors::PUNCTUATION);
+                push_colored(&mut output, ",", theme.punctuation, target);
                 // After comma in object, expect key; in array, expect value
                 expect_key = brace_stack.last() == Some(&'{');
                 i += 1;
@@ -98,8 +201,8 @@ pub fn highlight_json(input: &str) -> String {
             // String (could be key or value)
             '"' => {
                 let (string_content, end_pos) = parse_string(&chars, i);
-                let color = if expect_key { colors::KEY } else { colors::STRING };
-                push_colored(&mut output, &string_content, color);
+                let color = if expect_key { theme.key } else { theme.string };
+                push_colored(&mut output, &string_content, color, target);
                 expect_key = false;
                 i = end_pos;
             }
@@ -107,45 +210,49 @@ pub fn highlight_json(input: &str) -> String {
             // Number
             '-' | '0'..='9' => {
                 let (num_str, end_pos) = parse_number(&chars, i);
-                push_colored(&mut output, &num_str, colors::NUMBER);
+                push_colored(&mut output, &num_str, theme.number, target);
                 expect_key = false;
                 i = end_pos;
             }
 
             // true
             't' if matches_keyword(&chars, i, "true") => {
-                push_colored(&mut output, "true", colors::BOOLEAN);
+                push_colored(&mut output, "true", theme.boolean, target);
                 expect_key = false;
                 i += 4;
             }
 
             // false
             'f' if matches_keyword(&chars, i, "false") => {
-                push_colored(&mut output, "false", colors::BOOLEAN);
+                push_colored(&mut output, "false", theme.boolean, target);
                 expect_key = false;
                 i += 5;
             }
 
             // null
             'n' if matches_keyword(&chars, i, "null") => {
-                push_colored(&mut output, "null", colors::NULL);
+                push_colored(&mut output, "null", theme.null, target);
                 expect_key = false;
                 i += 4;
             }
 
-            // Unknown character - just escape and output
+            // Unknown character - just escape (HTML) or pass through (ANSI)
             _ => {
-                push_escaped(&mut output, c);
+                push_plain(&mut output, c, target);
                 i += 1;
             }
         }
     }
 
-    output.push_str("</pre>");
+    if target == OutputTarget::Html {
+        output.push_str("</pre>");
+    }
     output
 }
 
-/// Parse a JSON string starting at position i, returns (string_with_quotes, end_position)
+/// Parse a JSON string starting at position i, returns (string_with_quotes, end_position).
+/// HTML-escaping of the content is deferred to [`push_colored`] since it only
+/// applies to the `Html` output target.
 fn parse_string(chars: &[char], start: usize) -> (String, usize) {
     let mut result = String::new();
     result.push('"');
@@ -165,18 +272,6 @@ fn parse_string(chars: &[char], start: usize) -> (String, usize) {
                 result.push(chars[i + 1]);
                 i += 2;
             }
-            '<' => {
-                result.push_str("&lt;");
-                i += 1;
-            }
-            '>' => {
-                result.push_str("&gt;");
-                i += 1;
-            }
-            '&' => {
-                result.push_str("&amp;");
-                i += 1;
-            }
             _ => {
                 result.push(c);
                 i += 1;
@@ -252,17 +347,36 @@ fn matches_keyword(chars: &[char], start: usize, keyword: &str) -> bool {
     true
 }
 
-/// Push colored HTML span
-fn push_colored(output: &mut String, text: &str, color: &str) {
-    output.push_str("<span style=\"color:");
-    output.push_str(color);
-    output.push_str("\">");
-    output.push_str(text);
-    output.push_str("</span>");
+/// Push a span of colored text to the output, in the given target format.
+pub(crate) fn push_colored(output: &mut String, text: &str, color: Color, target: OutputTarget) {
+    match target {
+        OutputTarget::Html => {
+            output.push_str("<span style=\"color:");
+            output.push_str(&color.to_hex());
+            output.push_str("\">");
+            for c in text.chars() {
+                push_escaped_char(output, c);
+            }
+            output.push_str("</span>");
+        }
+        OutputTarget::Ansi => {
+            output.push_str(&color.to_ansi_fg());
+            output.push_str(text);
+            output.push_str("\x1b[0m");
+        }
+    }
+}
+
+/// Push a single uncolored character, HTML-escaping it for the `Html` target.
+pub(crate) fn push_plain(output: &mut String, c: char, target: OutputTarget) {
+    match target {
+        OutputTarget::Html => push_escaped_char(output, c),
+        OutputTarget::Ansi => output.push(c),
+    }
 }
 
-/// Push escaped character
-fn push_escaped(output: &mut String, c: char) {
+/// HTML-escape a single character.
+fn push_escaped_char(output: &mut String, c: char) {
     match c {
         '<' => output.push_str("&lt;"),
         '>' => output.push_str("&gt;"),
@@ -318,10 +432,11 @@ mod tests {
     fn test_highlight_key_vs_value_colors() {
         let input = r#"{"myKey": "myValue"}"#;
         let result = highlight_json(input);
-        // Key should have KEY color
-        assert!(result.contains(&format!("color:{}", colors::KEY)));
-        // Value should have STRING color
-        assert!(result.contains(&format!("color:{}", colors::STRING)));
+        let theme = Theme::dark();
+        // Key should have the theme's key color
+        assert!(result.contains(&format!("color:{}", theme.key.to_hex())));
+        // Value should have the theme's string color
+        assert!(result.contains(&format!("color:{}", theme.string.to_hex())));
     }
 
     #[test]
@@ -331,4 +446,47 @@ mod tests {
         assert!(result.contains("&lt;script&gt;"));
         assert!(!result.contains("<script>"));
     }
+
+    #[test]
+    fn test_highlight_light_theme() {
+        let input = r#"{"key": "value"}"#;
+        let result = highlight_json_with_theme(input, &Theme::light(), OutputTarget::Html);
+        assert!(result.contains(&format!("color:{}", Theme::light().key.to_hex())));
+        assert!(!result.contains(&format!("color:{}", Theme::dark().key.to_hex())));
+    }
+
+    #[test]
+    fn test_highlight_custom_theme() {
+        let custom = Theme {
+            string: Color::new(1, 2, 3),
+            ..Theme::dark()
+        };
+        let input = r#"{"k": "v"}"#;
+        let result = highlight_json_with_theme(input, &custom, OutputTarget::Html);
+        assert!(result.contains("color:#010203"));
+    }
+
+    #[test]
+    fn test_highlight_ansi_output_has_no_html() {
+        let input = r#"{"key": "value", "num": 42, "ok": true, "n": null}"#;
+        let result = highlight_json_with_theme(input, &Theme::dark(), OutputTarget::Ansi);
+        assert!(!result.contains("<span"));
+        assert!(!result.contains("<pre"));
+        assert!(result.contains("\x1b[38;2;"));
+        assert!(result.contains("\x1b[0m"));
+        assert!(result.contains("key"));
+        assert!(result.contains("42"));
+    }
+
+    #[test]
+    fn test_highlight_ansi_preserves_angle_brackets() {
+        let input = r#"{"test": "<tag>"}"#;
+        let result = highlight_json_with_theme(input, &Theme::dark(), OutputTarget::Ansi);
+        assert!(result.contains("<tag>"));
+    }
+
+    #[test]
+    fn test_color_to_hex() {
+        assert_eq!(Color::new(0xce, 0x91, 0x78).to_hex(), "#ce9178");
+    }
 }
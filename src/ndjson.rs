@@ -0,0 +1,124 @@
+//! NDJSON / concatenated-JSON-stream formatting and minification.
+//!
+//! Logs, JSONL datasets, and API event dumps are commonly shipped as a
+//! sequence of whitespace- or newline-separated JSON values rather than a
+//! single document. This module formats or minifies each record
+//! independently using `serde_json`'s streaming deserializer, so a stream
+//! doesn't need to fit in memory as one parsed `Value` tree and a bad
+//! record deep in a large file can be located precisely.
+
+use crate::formatter::format_value;
+use crate::types::FormatError;
+use crate::types::IndentStyle;
+use serde_json::{Deserializer, Value};
+
+/// Pretty-print every JSON record in an NDJSON / concatenated-JSON stream.
+///
+/// Each record is formatted independently with `indent` and records are
+/// separated by a blank line, since a pretty-printed record may itself span
+/// multiple lines. Use [`minify_ndjson`] for strict one-record-per-line
+/// NDJSON output.
+///
+/// # Arguments
+/// * `input` - Newline-delimited or whitespace-concatenated JSON records
+/// * `indent` - The indentation style to use for each record
+///
+/// # Returns
+/// * `Ok(String)` - The formatted records, separated by blank lines
+/// * `Err(FormatError)` - The failing record's index and line/column within
+///   the whole input
+pub fn format_ndjson(input: &str, indent: IndentStyle) -> Result<String, FormatError> {
+    let indent_str = indent.as_str();
+    let mut output = String::with_capacity(input.len() * 2);
+
+    for (index, record) in Deserializer::from_str(input).into_iter::<Value>().enumerate() {
+        let value = record
+            .map_err(|e| FormatError::with_record(e.to_string(), e.line(), e.column(), index))?;
+
+        if index > 0 {
+            output.push_str("\n\n");
+        }
+        format_value(&value, &indent_str, 0, false, &mut output);
+    }
+
+    Ok(output)
+}
+
+/// Minify every JSON record in an NDJSON / concatenated-JSON stream into
+/// strict newline-delimited JSON: one compact record per line.
+///
+/// # Arguments
+/// * `input` - Newline-delimited or whitespace-concatenated JSON records
+///
+/// # Returns
+/// * `Ok(String)` - One minified record per line
+/// * `Err(FormatError)` - The failing record's index and line/column within
+///   the whole input
+pub fn minify_ndjson(input: &str) -> Result<String, FormatError> {
+    let mut output = String::with_capacity(input.len());
+
+    for (index, record) in Deserializer::from_str(input).into_iter::<Value>().enumerate() {
+        let value = record
+            .map_err(|e| FormatError::with_record(e.to_string(), e.line(), e.column(), index))?;
+
+        if index > 0 {
+            output.push('\n');
+        }
+        let compact = serde_json::to_string(&value)
+            .map_err(|e| FormatError::with_record(e.to_string(), 0, 0, index))?;
+        output.push_str(&compact);
+    }
+
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_minify_ndjson_basic() {
+        let input = "{\"a\":1}\n{\"b\":2}\n";
+        let result = minify_ndjson(input).unwrap();
+        assert_eq!(result, "{\"a\":1}\n{\"b\":2}");
+    }
+
+    #[test]
+    fn test_minify_ndjson_concatenated_no_newlines() {
+        let input = r#"{"a":1}{"b":2}{"c":3}"#;
+        let result = minify_ndjson(input).unwrap();
+        assert_eq!(result, "{\"a\":1}\n{\"b\":2}\n{\"c\":3}");
+    }
+
+    #[test]
+    fn test_format_ndjson_basic() {
+        let input = "{\"a\":1}\n{\"b\":2}\n";
+        let result = format_ndjson(input, IndentStyle::Spaces(2)).unwrap();
+        assert_eq!(result, "{\n  \"a\": 1\n}\n\n{\n  \"b\": 2\n}");
+    }
+
+    #[test]
+    fn test_ndjson_error_reports_record_index() {
+        let input = "{\"a\":1}\n{\"b\": invalid}\n";
+        let result = minify_ndjson(input);
+        assert!(result.is_err());
+        let err = result.unwrap_err();
+        assert_eq!(err.record_index, Some(1));
+        assert_eq!(err.line, 2);
+    }
+
+    #[test]
+    fn test_ndjson_error_position_is_within_whole_input() {
+        let input = "{\"ok\":1}\n{\"ok\":2}\n{\"bad\": }\n";
+        let result = minify_ndjson(input);
+        let err = result.unwrap_err();
+        assert_eq!(err.record_index, Some(2));
+        assert_eq!(err.line, 3);
+    }
+
+    #[test]
+    fn test_minify_ndjson_empty_input() {
+        let result = minify_ndjson("").unwrap();
+        assert_eq!(result, "");
+    }
+}
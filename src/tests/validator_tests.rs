@@ -1,5 +1,6 @@
 use crate::formatter::minify_json;
-use crate::validator::validate_json;
+use crate::types::{ParseMode, ValidationIssue};
+use crate::validator::{validate_json, validate_json_with_mode};
 
 #[test]
 fn test_validate_valid_simple_object() {
@@ -189,3 +190,73 @@ fn test_validate_unicode() {
     assert!(result.is_valid);
     assert_eq!(result.stats.string_count, 2);
 }
+
+#[test]
+fn test_strict_mode_trailing_decimal_point() {
+    let input = r#"{"n": 1.}"#;
+    let result = validate_json_with_mode(input, ParseMode::Strict);
+    assert!(!result.is_valid);
+    assert_eq!(result.issue, Some(ValidationIssue::TrailingDecimalPoint));
+}
+
+#[test]
+fn test_strict_mode_leading_zero() {
+    let input = r#"{"n": 01}"#;
+    let result = validate_json_with_mode(input, ParseMode::Strict);
+    assert!(!result.is_valid);
+    assert_eq!(result.issue, Some(ValidationIssue::LeadingZero));
+}
+
+#[test]
+fn test_strict_mode_bare_plus_sign() {
+    let input = r#"{"n": +1}"#;
+    let result = validate_json_with_mode(input, ParseMode::Strict);
+    assert!(!result.is_valid);
+    assert_eq!(result.issue, Some(ValidationIssue::BarePlusSign));
+}
+
+#[test]
+fn test_strict_mode_trailing_comma() {
+    let input = r#"{"a": 1,}"#;
+    let result = validate_json_with_mode(input, ParseMode::Strict);
+    assert!(!result.is_valid);
+    assert_eq!(result.issue, Some(ValidationIssue::TrailingComma));
+}
+
+#[test]
+fn test_strict_mode_non_ascii_bare_identifier() {
+    let input = "{caf\u{e9}: 1}";
+    let result = validate_json_with_mode(input, ParseMode::Strict);
+    assert!(!result.is_valid);
+    assert_eq!(result.issue, Some(ValidationIssue::NonAsciiBareIdentifier));
+}
+
+#[test]
+fn test_lenient_mode_accepts_trailing_comma() {
+    let input = r#"{"a": 1, "b": 2,}"#;
+    let result = validate_json_with_mode(input, ParseMode::Lenient);
+    assert!(result.is_valid);
+    assert_eq!(result.stats.total_keys, 2);
+}
+
+#[test]
+fn test_lenient_mode_accepts_line_comment() {
+    let input = "{\n  // a comment\n  \"a\": 1\n}";
+    let result = validate_json_with_mode(input, ParseMode::Lenient);
+    assert!(result.is_valid);
+}
+
+#[test]
+fn test_lenient_mode_accepts_block_comment() {
+    let input = r#"{ /* note */ "a": 1 }"#;
+    let result = validate_json_with_mode(input, ParseMode::Lenient);
+    assert!(result.is_valid);
+}
+
+#[test]
+fn test_default_validate_json_is_strict() {
+    let input = r#"{"a": 1,}"#;
+    let result = validate_json(input);
+    assert!(!result.is_valid);
+    assert_eq!(result.issue, Some(ValidationIssue::TrailingComma));
+}
@@ -25,12 +25,40 @@ impl IndentStyle {
     }
 }
 
+/// Key ordering strategy for formatted or minified JSON output.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum KeyOrder {
+    /// Keep object keys in the order they appeared in the source document.
+    #[default]
+    Preserve,
+    /// Canonicalize output by sorting object keys alphabetically.
+    Sorted,
+}
+
+/// Options controlling how JSON is formatted or minified.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct FormatOptions {
+    /// Indentation style to use for pretty-printing.
+    pub indent: IndentStyle,
+    /// Whether to preserve source key order or sort keys alphabetically.
+    pub key_order: KeyOrder,
+    /// When `true`, escape every code point above U+007F as `\uXXXX` (astral
+    /// code points as UTF-16 surrogate pairs) so output is strictly 7-bit
+    /// ASCII. Mirrors the `ascii_only` generator flag in the Ruby JSON
+    /// library; useful for airgapped transfer across channels that mangle
+    /// UTF-8. Defaults to `false`, emitting UTF-8 directly.
+    pub ascii_only: bool,
+}
+
 /// Error that occurs during JSON formatting or parsing.
 #[derive(Clone, Debug, PartialEq)]
 pub struct FormatError {
     pub message: String,
     pub line: usize,
     pub column: usize,
+    /// Index of the record that failed, for multi-document inputs like NDJSON.
+    /// `None` for errors from a single JSON document.
+    pub record_index: Option<usize>,
 }
 
 impl FormatError {
@@ -39,17 +67,41 @@ impl FormatError {
             message: message.into(),
             line,
             column,
+            record_index: None,
+        }
+    }
+
+    /// Create a `FormatError` for a specific record in a multi-document stream
+    /// (e.g. NDJSON), where `line`/`column` are positions within the whole input.
+    pub fn with_record(
+        message: impl Into<String>,
+        line: usize,
+        column: usize,
+        record_index: usize,
+    ) -> Self {
+        Self {
+            message: message.into(),
+            line,
+            column,
+            record_index: Some(record_index),
         }
     }
 }
 
 impl fmt::Display for FormatError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "Error at line {}, column {}: {}",
-            self.line, self.column, self.message
-        )
+        match self.record_index {
+            Some(idx) => write!(
+                f,
+                "Error in record {} at line {}, column {}: {}",
+                idx, self.line, self.column, self.message
+            ),
+            None => write!(
+                f,
+                "Error at line {}, column {}: {}",
+                self.line, self.column, self.message
+            ),
+        }
     }
 }
 
@@ -68,12 +120,44 @@ pub struct JsonStats {
     pub total_keys: usize,
 }
 
+/// How strictly a parser should interpret the JSON grammar.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum ParseMode {
+    /// Reject anything outside the strict JSON grammar, with diagnostics
+    /// that name the exact defect (see [`ValidationIssue`]).
+    #[default]
+    Strict,
+    /// Normalize common non-standard extensions (trailing commas, `//` and
+    /// `/* */` comments) before parsing, accepting them instead of erroring.
+    Lenient,
+}
+
+/// A specific JSON grammar defect detected while validating in
+/// [`ParseMode::Strict`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ValidationIssue {
+    /// A decimal point with no fractional digit after it, e.g. `1.`.
+    TrailingDecimalPoint,
+    /// An integer with a leading zero, e.g. `01`.
+    LeadingZero,
+    /// A `+` sign outside a string that does not directly follow an exponent
+    /// marker (`e`/`E`), e.g. the leading `+` in `+1`.
+    BarePlusSign,
+    /// A non-ASCII identifier character outside of a string literal.
+    NonAsciiBareIdentifier,
+    /// A trailing comma before a closing `}` or `]`.
+    TrailingComma,
+}
+
 /// Result of validating a JSON document.
 #[derive(Clone, Debug)]
 pub struct ValidationResult {
     pub is_valid: bool,
     pub error: Option<FormatError>,
     pub stats: JsonStats,
+    /// The specific grammar defect detected, when validating in
+    /// [`ParseMode::Strict`] and a known defect was found.
+    pub issue: Option<ValidationIssue>,
 }
 
 impl ValidationResult {
@@ -83,6 +167,7 @@ impl ValidationResult {
             is_valid: true,
             error: None,
             stats,
+            issue: None,
         }
     }
 
@@ -92,6 +177,17 @@ impl ValidationResult {
             is_valid: false,
             error: Some(error),
             stats: JsonStats::default(),
+            issue: None,
+        }
+    }
+
+    /// Create a validation result for invalid JSON with a classified defect.
+    pub fn invalid_with_issue(error: FormatError, issue: ValidationIssue) -> Self {
+        Self {
+            is_valid: false,
+            error: Some(error),
+            stats: JsonStats::default(),
+            issue: Some(issue),
         }
     }
 }
@@ -112,12 +208,35 @@ mod tests {
         assert_eq!(IndentStyle::Tabs.as_str(), "\t");
     }
 
+    #[test]
+    fn test_key_order_default() {
+        assert_eq!(KeyOrder::default(), KeyOrder::Preserve);
+    }
+
+    #[test]
+    fn test_format_options_default() {
+        let opts = FormatOptions::default();
+        assert_eq!(opts.indent, IndentStyle::Spaces(4));
+        assert_eq!(opts.key_order, KeyOrder::Preserve);
+        assert!(!opts.ascii_only);
+    }
+
     #[test]
     fn test_format_error_display() {
         let err = FormatError::new("unexpected token", 5, 10);
         assert_eq!(err.to_string(), "Error at line 5, column 10: unexpected token");
     }
 
+    #[test]
+    fn test_format_error_with_record_display() {
+        let err = FormatError::with_record("unexpected token", 5, 10, 3);
+        assert_eq!(
+            err.to_string(),
+            "Error in record 3 at line 5, column 10: unexpected token"
+        );
+        assert_eq!(err.record_index, Some(3));
+    }
+
     #[test]
     fn test_format_error_new() {
         let err = FormatError::new("test error", 1, 2);
@@ -154,5 +273,19 @@ mod tests {
         assert!(!result.is_valid);
         assert!(result.error.is_some());
         assert_eq!(result.error.unwrap().message, "syntax error");
+        assert!(result.issue.is_none());
+    }
+
+    #[test]
+    fn test_validation_result_invalid_with_issue() {
+        let err = FormatError::new("leading zero", 1, 2);
+        let result = ValidationResult::invalid_with_issue(err, ValidationIssue::LeadingZero);
+        assert!(!result.is_valid);
+        assert_eq!(result.issue, Some(ValidationIssue::LeadingZero));
+    }
+
+    #[test]
+    fn test_parse_mode_default() {
+        assert_eq!(ParseMode::default(), ParseMode::Strict);
     }
 }
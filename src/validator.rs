@@ -1,15 +1,63 @@
-use crate::types::{FormatError, JsonStats, ValidationResult};
+use crate::types::{FormatError, JsonStats, ParseMode, ValidationIssue, ValidationResult};
 use serde_json::Value;
 
 /// Validate JSON and return statistics about its structure.
 ///
+/// Objects are parsed with source key order preserved, so stats such as
+/// `total_keys` reflect the document's real structure rather than an
+/// alphabetically-reordered view of it. Uses [`ParseMode::Strict`]; see
+/// [`validate_json_with_mode`] to accept trailing commas and comments.
+///
 /// # Arguments
 /// * `input` - The JSON string to validate
 ///
 /// # Returns
 /// * `ValidationResult` containing validity status, error info (if invalid), and statistics
 pub fn validate_json(input: &str) -> ValidationResult {
+    validate_json_with_mode(input, ParseMode::default())
+}
+
+/// Validate JSON under an explicit [`ParseMode`].
+///
+/// In [`ParseMode::Strict`], a parse failure is classified against the
+/// grammar defects in [`ValidationIssue`] (trailing decimal point, leading
+/// zero, bare `+`, non-ASCII bare identifier, trailing comma) when one of
+/// them is the cause, so callers can branch on defect kind rather than
+/// string-matching the error message. In [`ParseMode::Lenient`], trailing
+/// commas and `//`/`/* */` comments are normalized away before parsing.
+///
+/// # Arguments
+/// * `input` - The JSON string to validate
+/// * `mode` - How strictly to interpret the JSON grammar
+///
+/// # Returns
+/// * `ValidationResult` containing validity status, error info (if invalid), and statistics
+pub fn validate_json_with_mode(input: &str, mode: ParseMode) -> ValidationResult {
+    match mode {
+        ParseMode::Strict => validate_strict(input),
+        ParseMode::Lenient => validate_lenient(input),
+    }
+}
+
+fn validate_strict(input: &str) -> ValidationResult {
     match serde_json::from_str::<Value>(input) {
+        Ok(value) => {
+            let mut stats = JsonStats::default();
+            collect_stats(&value, 0, &mut stats);
+            ValidationResult::valid(stats)
+        }
+        Err(e) => match find_strict_violation(input) {
+            Some((issue, line, column, message)) => {
+                ValidationResult::invalid_with_issue(FormatError::new(message, line, column), issue)
+            }
+            None => ValidationResult::invalid(FormatError::new(e.to_string(), e.line(), e.column())),
+        },
+    }
+}
+
+fn validate_lenient(input: &str) -> ValidationResult {
+    let normalized = normalize_lenient(input);
+    match serde_json::from_str::<Value>(&normalized) {
         Ok(value) => {
             let mut stats = JsonStats::default();
             collect_stats(&value, 0, &mut stats);
@@ -22,6 +70,204 @@ pub fn validate_json(input: &str) -> ValidationResult {
     }
 }
 
+/// Strip `//` line comments, `/* */` block comments, and commas that are
+/// immediately followed (modulo whitespace) by a closing `}` or `]`, leaving
+/// clean JSON for `serde_json` to parse. Runs outside of string literals only.
+fn normalize_lenient(input: &str) -> String {
+    let chars: Vec<char> = input.chars().collect();
+    let len = chars.len();
+    let mut output = String::with_capacity(input.len());
+    let mut i = 0;
+    let mut in_string = false;
+
+    while i < len {
+        let c = chars[i];
+
+        if in_string {
+            output.push(c);
+            if c == '\\' && i + 1 < len {
+                output.push(chars[i + 1]);
+                i += 2;
+                continue;
+            }
+            if c == '"' {
+                in_string = false;
+            }
+            i += 1;
+            continue;
+        }
+
+        match c {
+            '"' => {
+                in_string = true;
+                output.push(c);
+                i += 1;
+            }
+            '/' if i + 1 < len && chars[i + 1] == '/' => {
+                while i < len && chars[i] != '\n' {
+                    i += 1;
+                }
+            }
+            '/' if i + 1 < len && chars[i + 1] == '*' => {
+                i += 2;
+                while i + 1 < len && !(chars[i] == '*' && chars[i + 1] == '/') {
+                    i += 1;
+                }
+                i = (i + 2).min(len);
+            }
+            ',' => {
+                let mut j = i + 1;
+                while j < len && chars[j].is_whitespace() {
+                    j += 1;
+                }
+                if j < len && (chars[j] == '}' || chars[j] == ']') {
+                    // Drop the trailing comma entirely.
+                } else {
+                    output.push(c);
+                }
+                i += 1;
+            }
+            _ => {
+                output.push(c);
+                i += 1;
+            }
+        }
+    }
+
+    output
+}
+
+/// Scan a document that `serde_json` already rejected for one of the
+/// specific grammar violations in [`ValidationIssue`], returning the defect
+/// kind, the line/column of the offending token, and a message naming it.
+/// Returns `None` if the failure doesn't match a known defect.
+fn find_strict_violation(input: &str) -> Option<(ValidationIssue, usize, usize, String)> {
+    let chars: Vec<char> = input.chars().collect();
+    let len = chars.len();
+    let mut i = 0;
+    let mut line = 1;
+    let mut column = 1;
+    let mut in_string = false;
+
+    while i < len {
+        let c = chars[i];
+
+        if in_string {
+            if c == '\\' && i + 1 < len {
+                advance(&chars, &mut i, &mut line, &mut column);
+            } else if c == '"' {
+                in_string = false;
+            }
+            advance(&chars, &mut i, &mut line, &mut column);
+            continue;
+        }
+
+        match c {
+            '"' => {
+                in_string = true;
+                advance(&chars, &mut i, &mut line, &mut column);
+            }
+            '+' => {
+                let prev_is_exponent = i > 0 && matches!(chars[i - 1], 'e' | 'E');
+                if !prev_is_exponent {
+                    return Some((
+                        ValidationIssue::BarePlusSign,
+                        line,
+                        column,
+                        "unexpected leading '+' sign".to_string(),
+                    ));
+                }
+                advance(&chars, &mut i, &mut line, &mut column);
+            }
+            '0'..='9' | '-' => {
+                let start_line = line;
+                let start_col = column;
+                if c == '-' {
+                    advance(&chars, &mut i, &mut line, &mut column);
+                }
+                let digits_start = i;
+                while i < len && chars[i].is_ascii_digit() {
+                    advance(&chars, &mut i, &mut line, &mut column);
+                }
+                if i - digits_start > 1 && chars[digits_start] == '0' {
+                    return Some((
+                        ValidationIssue::LeadingZero,
+                        start_line,
+                        start_col,
+                        "leading-zero integers are not allowed".to_string(),
+                    ));
+                }
+                if i < len && chars[i] == '.' {
+                    let dot_line = line;
+                    let dot_col = column;
+                    advance(&chars, &mut i, &mut line, &mut column);
+                    if i >= len || !chars[i].is_ascii_digit() {
+                        return Some((
+                            ValidationIssue::TrailingDecimalPoint,
+                            dot_line,
+                            dot_col,
+                            "decimal point must be followed by at least one digit".to_string(),
+                        ));
+                    }
+                    while i < len && chars[i].is_ascii_digit() {
+                        advance(&chars, &mut i, &mut line, &mut column);
+                    }
+                }
+                if i < len && (chars[i] == 'e' || chars[i] == 'E') {
+                    advance(&chars, &mut i, &mut line, &mut column);
+                    if i < len && (chars[i] == '+' || chars[i] == '-') {
+                        advance(&chars, &mut i, &mut line, &mut column);
+                    }
+                    while i < len && chars[i].is_ascii_digit() {
+                        advance(&chars, &mut i, &mut line, &mut column);
+                    }
+                }
+            }
+            ',' => {
+                let comma_line = line;
+                let comma_col = column;
+                advance(&chars, &mut i, &mut line, &mut column);
+                let mut j = i;
+                while j < len && chars[j].is_whitespace() {
+                    j += 1;
+                }
+                if j < len && (chars[j] == '}' || chars[j] == ']') {
+                    return Some((
+                        ValidationIssue::TrailingComma,
+                        comma_line,
+                        comma_col,
+                        "trailing comma before closing bracket".to_string(),
+                    ));
+                }
+            }
+            c if !c.is_ascii() && (c.is_alphabetic() || c == '_') => {
+                return Some((
+                    ValidationIssue::NonAsciiBareIdentifier,
+                    line,
+                    column,
+                    format!("unexpected non-ASCII identifier character '{}'", c),
+                ));
+            }
+            _ => {
+                advance(&chars, &mut i, &mut line, &mut column);
+            }
+        }
+    }
+
+    None
+}
+
+/// Advance the scan cursor by one character, tracking line/column.
+fn advance(chars: &[char], i: &mut usize, line: &mut usize, column: &mut usize) {
+    if chars[*i] == '\n' {
+        *line += 1;
+        *column = 1;
+    } else {
+        *column += 1;
+    }
+    *i += 1;
+}
+
 /// Recursively collect statistics from a JSON value tree.
 fn collect_stats(value: &Value, depth: usize, stats: &mut JsonStats) {
     // Update max depth